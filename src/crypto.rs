@@ -0,0 +1,362 @@
+use std::{
+  collections::HashMap,
+  marker::PhantomData,
+  path::PathBuf,
+  sync::{Arc, Mutex},
+};
+
+use cdr::{CdrLe, Infinite};
+use chacha20poly1305::{
+  aead::{Aead, KeyInit},
+  ChaCha20Poly1305, Key, Nonce,
+};
+use futures::stream::{FusedStream, Stream, StreamExt};
+use rand::RngCore;
+use rustdds::{dds::ReadResult, *};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::pubsub::{MessageInfo, Publisher, Subscription};
+
+const NONCE_LEN: usize = 12; // 96 bits: 32-bit session prefix + 64-bit counter
+const TAG_LEN: usize = 16; // ChaCha20-Poly1305 authentication tag
+
+/// A 256-bit symmetric key for encrypting one topic's payloads.
+pub type TopicKey = [u8; 32];
+
+/// Supplies the per-topic symmetric key used by [`EncryptedPublisher`] and
+/// [`EncryptedSubscription`].
+///
+/// ros2-client does not implement key exchange or distribution itself:
+/// applications are expected to plug in whatever mechanism they already use
+/// (a secrets manager, a DDS-Security-like key exchange, a config file, ...)
+/// by implementing this trait.
+pub trait TopicKeyProvider {
+  /// Returns the current key for `topic_name`, or `None` if this provider
+  /// does not have (or does not want to hand out) a key for that topic.
+  fn key_for_topic(&self, topic_name: &str) -> Option<TopicKey>;
+}
+
+/// Error produced while encrypting an outgoing message.
+#[derive(Debug)]
+pub enum EncryptError {
+  /// CDR-serializing the plaintext message failed.
+  Serialize(cdr::Error),
+  /// The AEAD cipher refused to encrypt the plaintext.
+  Seal,
+  /// Handing the encrypted payload to the underlying `Publisher` failed.
+  Write,
+}
+
+impl std::fmt::Display for EncryptError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      EncryptError::Serialize(e) => write!(f, "CDR serialization failed: {e}"),
+      EncryptError::Seal => write!(f, "AEAD encryption failed"),
+      EncryptError::Write => write!(f, "writing encrypted payload failed"),
+    }
+  }
+}
+
+impl std::error::Error for EncryptError {}
+
+/// Persists the next unused nonce counter for a writer GUID across process
+/// restarts, so a writer resuming under the same topic key can never reuse
+/// a nonce an earlier process already used.
+///
+/// [`EncryptedPublisher`] additionally randomizes a 32-bit session prefix on
+/// top of whatever this returns, but the prefix alone is not a substitute
+/// for persistence: it only gives a probabilistic (birthday-bound, roughly
+/// 50% after ~2^16 restarts under the same key) guarantee against reuse.
+pub trait NonceCounterStore: Send + Sync {
+  /// Returns the first unused counter value for `writer_guid`, durably
+  /// recording that it (and everything before it) is now spent before
+  /// returning, so a crash immediately after this call cannot cause reuse.
+  fn take_and_advance(&self, writer_guid: GUID) -> u64;
+}
+
+/// A [`NonceCounterStore`] that keeps counters in memory only, restarting
+/// every writer at 0 on every process start.
+///
+/// Use this only when every `EncryptedPublisher` is given a fresh topic key
+/// for each process lifetime (e.g. keys derived from a per-run secret); if
+/// the same key can outlive a process restart, use
+/// [`FileNonceCounterStore`] instead.
+#[derive(Default)]
+pub struct InMemoryNonceCounterStore {
+  counters: Mutex<HashMap<GUID, u64>>,
+}
+
+impl NonceCounterStore for InMemoryNonceCounterStore {
+  fn take_and_advance(&self, writer_guid: GUID) -> u64 {
+    let mut counters = self.counters.lock().unwrap();
+    let counter = counters.entry(writer_guid).or_insert(0);
+    let value = *counter;
+    *counter += 1;
+    value
+  }
+}
+
+/// A [`NonceCounterStore`] backed by a small JSON file, so the counter
+/// survives process restarts as the DDS-Security-style nonce scheme
+/// requires. Every call synchronously rewrites the whole file; this trades
+/// write amplification for the file always holding the true high-water
+/// mark, rather than reserving counter blocks ahead of time.
+pub struct FileNonceCounterStore {
+  path: PathBuf,
+  table: Mutex<HashMap<String, u64>>,
+}
+
+impl FileNonceCounterStore {
+  pub fn new(path: impl Into<PathBuf>) -> FileNonceCounterStore {
+    let path = path.into();
+    let table = std::fs::read_to_string(&path)
+      .ok()
+      .and_then(|contents| serde_json::from_str(&contents).ok())
+      .unwrap_or_default();
+    FileNonceCounterStore { path, table: Mutex::new(table) }
+  }
+}
+
+impl NonceCounterStore for FileNonceCounterStore {
+  fn take_and_advance(&self, writer_guid: GUID) -> u64 {
+    let mut table = self.table.lock().unwrap();
+    let key = writer_guid.to_string();
+    let counter = table.entry(key).or_insert(0);
+    let value = *counter;
+    *counter += 1;
+    // Handing out a counter that was not recorded could lead to nonce reuse
+    // after a restart, so a failed write is fatal rather than ignored.
+    let json = serde_json::to_string(&*table).expect("FileNonceCounterStore: cannot serialize counter table");
+    if let Err(e) = std::fs::write(&self.path, json) {
+      panic!("FileNonceCounterStore: cannot record nonce counter in {}: {e}", self.path.display());
+    }
+    value
+  }
+}
+
+// Generates nonces that never repeat for the lifetime of one writer's key:
+// a random 32-bit session prefix chosen at construction time, followed by a
+// 64-bit counter persisted per writer GUID by a NonceCounterStore.
+struct NonceSequence {
+  session_prefix: [u8; 4],
+  writer_guid: GUID,
+  counter_store: Arc<dyn NonceCounterStore>,
+}
+
+impl NonceSequence {
+  fn new(writer_guid: GUID, counter_store: Arc<dyn NonceCounterStore>) -> Self {
+    let mut session_prefix = [0u8; 4];
+    rand::thread_rng().fill_bytes(&mut session_prefix);
+    NonceSequence { session_prefix, writer_guid, counter_store }
+  }
+
+  fn next(&self) -> [u8; NONCE_LEN] {
+    let counter = self.counter_store.take_and_advance(self.writer_guid);
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce[0..4].copy_from_slice(&self.session_prefix);
+    nonce[4..12].copy_from_slice(&counter.to_be_bytes());
+    nonce
+  }
+}
+
+// Encrypts `plaintext` under `cipher` with `nonce_bytes`, producing the
+// on-the-wire payload `nonce ‖ ciphertext ‖ tag`. Kept free of Publisher so
+// it can be unit-tested without a live DDS participant.
+fn seal(cipher: &ChaCha20Poly1305, nonce_bytes: [u8; NONCE_LEN], plaintext: &[u8]) -> Result<Vec<u8>, EncryptError> {
+  let ciphertext = cipher
+    .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+    .map_err(|_| EncryptError::Seal)?;
+
+  let mut payload = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+  payload.extend_from_slice(&nonce_bytes);
+  payload.extend_from_slice(&ciphertext);
+  Ok(payload)
+}
+
+// Reverses `seal`: splits `payload` into nonce and ciphertext, and verifies
+// the tag. Kept free of Subscription for the same reason as `seal`.
+fn open(cipher: &ChaCha20Poly1305, payload: &[u8]) -> ReadResult<Vec<u8>> {
+  if payload.len() < NONCE_LEN + TAG_LEN {
+    return read_error_internal!(format!(
+      "EncryptedSubscription: payload of {} bytes is too short to contain a nonce and tag",
+      payload.len()
+    ));
+  }
+  let (nonce_bytes, ciphertext) = payload.split_at(NONCE_LEN);
+  match cipher.decrypt(Nonce::from_slice(nonce_bytes), ciphertext) {
+    Ok(plaintext) => Ok(plaintext),
+    Err(_) => read_error_internal!("EncryptedSubscription: AEAD authentication failed".to_string()),
+  }
+}
+
+/// A [`Publisher`] wrapper that CDR-serializes `M` and then encrypts it with
+/// ChaCha20-Poly1305 before it goes out on the wire.
+///
+/// The DDS payload is `nonce ‖ ciphertext ‖ tag`, where `nonce` is 12 bytes
+/// and `tag` is the 16-byte Poly1305 authentication tag appended by the AEAD
+/// cipher. The key is supplied once, at construction, by a
+/// [`TopicKeyProvider`].
+pub struct EncryptedPublisher<M: Serialize> {
+  publisher: Publisher<Vec<u8>>,
+  cipher: ChaCha20Poly1305,
+  nonces: NonceSequence,
+  phantom: PhantomData<M>,
+}
+
+impl<M: Serialize> EncryptedPublisher<M> {
+  // These must be created from Node, analogously to Publisher::new.
+  pub(crate) fn new(
+    publisher: Publisher<Vec<u8>>,
+    key: TopicKey,
+    counter_store: Arc<dyn NonceCounterStore>,
+  ) -> EncryptedPublisher<M> {
+    let writer_guid = publisher.guid();
+    EncryptedPublisher {
+      publisher,
+      cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+      nonces: NonceSequence::new(writer_guid, counter_store),
+      phantom: PhantomData,
+    }
+  }
+
+  pub fn publish(&self, message: M) -> Result<(), EncryptError> {
+    let plaintext =
+      cdr::serialize::<_, _, CdrLe>(&message, Infinite).map_err(EncryptError::Serialize)?;
+    let payload = seal(&self.cipher, self.nonces.next(), &plaintext)?;
+
+    self
+      .publisher
+      .publish(payload)
+      .map(|_| ())
+      .map_err(|_| EncryptError::Write)
+  }
+
+  pub fn guid(&self) -> rustdds::GUID {
+    self.publisher.guid()
+  }
+}
+
+/// A [`Subscription`] wrapper that reverses [`EncryptedPublisher`]: it
+/// splits the incoming payload into nonce, ciphertext, and tag, verifies the
+/// tag, and CDR-deserializes the plaintext into `M`. Authentication failure
+/// (wrong key, corrupted payload, or replay with a reused nonce under a
+/// different key) is reported as a [`ReadError`](rustdds::dds::ReadError).
+pub struct EncryptedSubscription<M> {
+  subscription: Subscription<Vec<u8>>,
+  cipher: ChaCha20Poly1305,
+  phantom: PhantomData<M>,
+}
+
+impl<M: 'static + DeserializeOwned> EncryptedSubscription<M> {
+  // These must be created from Node, analogously to Subscription::new.
+  pub(crate) fn new(subscription: Subscription<Vec<u8>>, key: TopicKey) -> EncryptedSubscription<M> {
+    EncryptedSubscription {
+      subscription,
+      cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+      phantom: PhantomData,
+    }
+  }
+
+  pub fn take(&self) -> ReadResult<Option<(M, MessageInfo)>> {
+    match self.subscription.take()? {
+      None => Ok(None),
+      Some((payload, message_info)) => Ok(Some((self.open(&payload)?, message_info))),
+    }
+  }
+
+  pub async fn async_take(&self) -> ReadResult<(M, MessageInfo)> {
+    let (payload, message_info) = self.subscription.async_take().await?;
+    Ok((self.open(&payload)?, message_info))
+  }
+
+  // Returns an async Stream of decrypted messages with MessageInfo metadata,
+  // mirroring Subscription::async_stream.
+  pub fn async_stream(&self) -> impl Stream<Item = ReadResult<(M, MessageInfo)>> + FusedStream + '_ {
+    self
+      .subscription
+      .async_stream()
+      .map(move |result| result.and_then(|(payload, message_info)| Ok((self.open(&payload)?, message_info))))
+  }
+
+  fn open(&self, payload: &[u8]) -> ReadResult<M> {
+    let plaintext = open(&self.cipher, payload)?;
+    match cdr::deserialize::<M>(&plaintext) {
+      Ok(value) => Ok(value),
+      Err(e) => read_error_internal!(format!("EncryptedSubscription: CDR decode failed: {e}")),
+    }
+  }
+
+  pub fn guid(&self) -> rustdds::GUID {
+    self.subscription.guid()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn test_key() -> TopicKey {
+    [7u8; 32]
+  }
+
+  fn cipher() -> ChaCha20Poly1305 {
+    ChaCha20Poly1305::new(Key::from_slice(&test_key()))
+  }
+
+  #[test]
+  fn seal_then_open_round_trips_the_plaintext() {
+    let cipher = cipher();
+    let payload = seal(&cipher, [1u8; NONCE_LEN], b"hello parameter event").unwrap();
+    let plaintext = open(&cipher, &payload).unwrap();
+    assert_eq!(plaintext, b"hello parameter event");
+  }
+
+  #[test]
+  fn open_rejects_a_tampered_ciphertext() {
+    let cipher = cipher();
+    let mut payload = seal(&cipher, [2u8; NONCE_LEN], b"authenticate me").unwrap();
+    let last = payload.len() - 1;
+    payload[last] ^= 0xFF; // flip every bit of the last byte, which is part of the authentication tag
+
+    assert!(open(&cipher, &payload).is_err());
+  }
+
+  #[test]
+  fn open_rejects_the_wrong_key() {
+    let payload = seal(&cipher(), [3u8; NONCE_LEN], b"top secret").unwrap();
+    let wrong_cipher = ChaCha20Poly1305::new(Key::from_slice(&[9u8; 32]));
+    assert!(open(&wrong_cipher, &payload).is_err());
+  }
+
+  #[test]
+  fn open_rejects_a_payload_too_short_for_nonce_and_tag() {
+    let short_payload = vec![0u8; NONCE_LEN + TAG_LEN - 1];
+    assert!(open(&cipher(), &short_payload).is_err());
+  }
+
+  #[test]
+  fn in_memory_nonce_counter_store_advances_monotonically() {
+    let store = InMemoryNonceCounterStore::default();
+    let writer = GUID::GUID_UNKNOWN;
+
+    assert_eq!(store.take_and_advance(writer), 0);
+    assert_eq!(store.take_and_advance(writer), 1);
+    assert_eq!(store.take_and_advance(writer), 2);
+  }
+
+  #[test]
+  fn file_nonce_counter_store_persists_across_instances() {
+    let path = std::env::temp_dir().join(format!("ros2_client_nonce_store_test_{}.json", rand::random::<u64>()));
+
+    {
+      let store = FileNonceCounterStore::new(&path);
+      assert_eq!(store.take_and_advance(GUID::GUID_UNKNOWN), 0);
+      assert_eq!(store.take_and_advance(GUID::GUID_UNKNOWN), 1);
+    }
+
+    let reopened = FileNonceCounterStore::new(&path);
+    assert_eq!(reopened.take_and_advance(GUID::GUID_UNKNOWN), 2);
+
+    let _ = std::fs::remove_file(&path);
+  }
+}
@@ -0,0 +1,133 @@
+use futures::lock::Mutex;
+use rustdds::{dds::ReadResult, *};
+
+use crate::{
+  parameters::{raw, Parameter, ParameterValue, SetParametersResult},
+  pubsub::{Publisher, Subscription},
+};
+
+// A request/response pair for calling one of the six standard parameter
+// services on a remote node. `call_lock` serializes publish-then-take per
+// endpoint, so only one request from a given ServiceCaller is in flight at
+// a time, and the next response that arrives is taken as its answer.
+// Responses are not matched against the request by their related sample
+// identity, so a response to another client's call on the same service is
+// not told apart from ours.
+struct ServiceCaller<Req: serde::Serialize, Resp> {
+  requests: Publisher<Req>,
+  responses: Subscription<Resp>,
+  call_lock: Mutex<()>,
+}
+
+impl<Req: serde::Serialize, Resp: 'static + serde::de::DeserializeOwned> ServiceCaller<Req, Resp> {
+  fn new(requests: Publisher<Req>, responses: Subscription<Resp>) -> ServiceCaller<Req, Resp> {
+    ServiceCaller { requests, responses, call_lock: Mutex::new(()) }
+  }
+
+  async fn call(&self, request: Req) -> ReadResult<Resp> {
+    let _guard = self.call_lock.lock().await;
+    self
+      .requests
+      .async_publish(request)
+      .await
+      .or_else(|_| read_error_internal!("ParameterClient: failed to send request".to_string()))?;
+    let (response, _info) = self.responses.async_take().await?;
+    Ok(response)
+  }
+}
+
+/// Remote counterpart to [`ParameterServer`](crate::parameter_server::ParameterServer):
+/// reads and writes another node's parameters over the same six
+/// `rcl_interfaces` services, so this node can act as the `ros2 param`
+/// tool would against the target node.
+pub struct ParameterClient {
+  target_node_fqn: String,
+  get_parameters: ServiceCaller<raw::GetParametersRequest, raw::GetParametersResponse>,
+  get_parameter_types: ServiceCaller<raw::GetParameterTypesRequest, raw::GetParameterTypesResponse>,
+  set_parameters: ServiceCaller<raw::SetParametersRequest, raw::SetParametersResponse>,
+  set_parameters_atomically:
+    ServiceCaller<raw::SetParametersAtomicallyRequest, raw::SetParametersAtomicallyResponse>,
+  list_parameters: ServiceCaller<raw::ListParametersRequest, raw::ListParametersResponse>,
+  describe_parameters: ServiceCaller<raw::DescribeParametersRequest, raw::DescribeParametersResponse>,
+}
+
+impl ParameterClient {
+  // These must be created from Node.
+  #[allow(clippy::too_many_arguments)]
+  pub(crate) fn new(
+    target_node_fqn: String,
+    get_parameters: (Publisher<raw::GetParametersRequest>, Subscription<raw::GetParametersResponse>),
+    get_parameter_types: (
+      Publisher<raw::GetParameterTypesRequest>,
+      Subscription<raw::GetParameterTypesResponse>,
+    ),
+    set_parameters: (Publisher<raw::SetParametersRequest>, Subscription<raw::SetParametersResponse>),
+    set_parameters_atomically: (
+      Publisher<raw::SetParametersAtomicallyRequest>,
+      Subscription<raw::SetParametersAtomicallyResponse>,
+    ),
+    list_parameters: (Publisher<raw::ListParametersRequest>, Subscription<raw::ListParametersResponse>),
+    describe_parameters: (
+      Publisher<raw::DescribeParametersRequest>,
+      Subscription<raw::DescribeParametersResponse>,
+    ),
+  ) -> ParameterClient {
+    ParameterClient {
+      target_node_fqn,
+      get_parameters: ServiceCaller::new(get_parameters.0, get_parameters.1),
+      get_parameter_types: ServiceCaller::new(get_parameter_types.0, get_parameter_types.1),
+      set_parameters: ServiceCaller::new(set_parameters.0, set_parameters.1),
+      set_parameters_atomically: ServiceCaller::new(set_parameters_atomically.0, set_parameters_atomically.1),
+      list_parameters: ServiceCaller::new(list_parameters.0, list_parameters.1),
+      describe_parameters: ServiceCaller::new(describe_parameters.0, describe_parameters.1),
+    }
+  }
+
+  /// The fully-qualified name of the node whose parameters this client
+  /// reads and writes.
+  pub fn target_node(&self) -> &str {
+    &self.target_node_fqn
+  }
+
+  pub async fn get_parameters(&self, names: Vec<String>) -> ReadResult<Vec<ParameterValue>> {
+    let resp = self.get_parameters.call(raw::GetParametersRequest { names }).await?;
+    Ok(resp.values.into_iter().map(ParameterValue::from).collect())
+  }
+
+  pub async fn get_parameter_types(&self, names: Vec<String>) -> ReadResult<Vec<u8>> {
+    let resp = self.get_parameter_types.call(raw::GetParameterTypesRequest { names }).await?;
+    Ok(resp.types)
+  }
+
+  pub async fn set_parameters(&self, parameters: Vec<Parameter>) -> ReadResult<Vec<SetParametersResult>> {
+    let req = raw::SetParametersRequest {
+      parameters: parameters.into_iter().map(raw::Parameter::from).collect(),
+    };
+    let resp = self.set_parameters.call(req).await?;
+    Ok(
+      resp
+        .results
+        .into_iter()
+        .map(|r| if r.successful { Ok(()) } else { Err(r.reason) })
+        .collect(),
+    )
+  }
+
+  pub async fn set_parameters_atomically(&self, parameters: Vec<Parameter>) -> ReadResult<SetParametersResult> {
+    let req = raw::SetParametersAtomicallyRequest {
+      parameters: parameters.into_iter().map(raw::Parameter::from).collect(),
+    };
+    let resp = self.set_parameters_atomically.call(req).await?;
+    Ok(if resp.result.successful { Ok(()) } else { Err(resp.result.reason) })
+  }
+
+  pub async fn list_parameters(&self, prefixes: Vec<String>, depth: u64) -> ReadResult<raw::ListParametersResult> {
+    let resp = self.list_parameters.call(raw::ListParametersRequest { prefixes, depth }).await?;
+    Ok(resp.result)
+  }
+
+  pub async fn describe_parameters(&self, names: Vec<String>) -> ReadResult<Vec<raw::ParameterDescriptor>> {
+    let resp = self.describe_parameters.call(raw::DescribeParametersRequest { names }).await?;
+    Ok(resp.descriptors)
+  }
+}
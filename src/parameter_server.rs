@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+
+use futures::FutureExt;
+use rustdds::{dds::ReadResult, rpc::WriteOptionsBuilder, Timestamp};
+
+use crate::{
+  parameters::{raw, Parameter, ParameterValue, SetParametersResult, PARAMETER_SEPARATOR},
+  pubsub::{MessageInfo, Publisher, Subscription},
+};
+
+// A prefix only contains a name if the match lands on a component
+// boundary: "motor.le" must not match "motor.left.gain", only a prefix like
+// "motor" or "motor.left" should.
+fn matches_prefix(name: &str, prefix: &str) -> bool {
+  name
+    .strip_prefix(prefix)
+    .map(|rest| rest.is_empty() || rest.starts_with(PARAMETER_SEPARATOR))
+    .unwrap_or(false)
+}
+
+// Mirrors rcl_interfaces ListParameters.srv: depth == DEPTH_RECURSIVE (0)
+// means no limit; otherwise a name only matches if it has at most `depth`
+// PARAMETER_SEPARATOR-separated components beneath whichever requested
+// prefix it matched (or beneath the root, if no prefixes were given).
+fn matches_depth(name: &str, prefixes: &[String], depth: u64) -> bool {
+  if depth == raw::LIST_PARAMETERS_DEPTH_RECURSIVE {
+    return true;
+  }
+  let remainder = match prefixes.iter().find(|pfx| matches_prefix(name, pfx)) {
+    Some(pfx) => name[pfx.len()..].trim_start_matches(PARAMETER_SEPARATOR),
+    None => name,
+  };
+  (remainder.matches(PARAMETER_SEPARATOR).count() as u64 + 1) <= depth
+}
+
+/// A validation hook that applications can install on a [`ParameterStore`]
+/// to accept or reject an incoming batch of parameter updates before they
+/// are applied.
+pub type ParameterValidator = Box<dyn FnMut(&[Parameter]) -> SetParametersResult + Send>;
+
+/// The backing store and decision logic for the six standard ROS2
+/// `rcl_interfaces` parameter services, kept separate from the DDS
+/// request/response plumbing in [`ParameterServer`] so it can be exercised
+/// without a `DomainParticipant`.
+pub struct ParameterStore {
+  node_fqn: String,
+  parameters: HashMap<String, ParameterValue>,
+  validator: Option<ParameterValidator>,
+}
+
+impl ParameterStore {
+  pub fn new(node_fqn: String) -> ParameterStore {
+    ParameterStore { node_fqn, parameters: HashMap::new(), validator: None }
+  }
+
+  /// Installs a hook that is asked to approve or reject every
+  /// `set_parameters`/`set_parameters_atomically` request before it is
+  /// applied. Without a hook, all updates are accepted.
+  pub fn set_validation_callback<F>(&mut self, validator: F)
+  where
+    F: FnMut(&[Parameter]) -> SetParametersResult + Send + 'static,
+  {
+    self.validator = Some(Box::new(validator));
+  }
+
+  /// Seeds the store with initial values, e.g. from
+  /// [`load_parameters_from_file`](crate::parameter_yaml::load_parameters_from_file),
+  /// without going through validation or emitting a `ParameterEvent`.
+  pub fn initialize(&mut self, parameters: impl IntoIterator<Item = Parameter>) {
+    for p in parameters {
+      self.parameters.insert(p.name, p.value);
+    }
+  }
+
+  fn validate(&mut self, parameters: &[Parameter]) -> SetParametersResult {
+    match &mut self.validator {
+      Some(validator) => validator(parameters),
+      None => Ok(()),
+    }
+  }
+
+  // Applies every parameter and returns the resulting ParameterEvent. Most
+  // parameters are inserted, splitting into "new" vs. "changed"; setting a
+  // parameter to NotSet is how ROS2 undeclares it, so that removes the key
+  // and reports it as deleted instead of storing NotSet as its value.
+  fn apply_all(&mut self, parameters: &[Parameter]) -> raw::ParameterEvent {
+    let mut new_parameters = Vec::new();
+    let mut changed_parameters = Vec::new();
+    let mut deleted_parameters = Vec::new();
+    for p in parameters {
+      if p.value == ParameterValue::NotSet {
+        if self.parameters.remove(&p.name).is_some() {
+          deleted_parameters.push(raw::Parameter::from(p.clone()));
+        }
+        continue;
+      }
+      let wire = raw::Parameter::from(p.clone());
+      match self.parameters.insert(p.name.clone(), p.value.clone()) {
+        None => new_parameters.push(wire),
+        Some(_) => changed_parameters.push(wire),
+      }
+    }
+    raw::ParameterEvent {
+      timestamp: Timestamp::now(),
+      node: self.node_fqn.clone(),
+      new_parameters,
+      changed_parameters,
+      deleted_parameters,
+    }
+  }
+
+  pub fn on_get_parameters(&self, req: raw::GetParametersRequest) -> raw::GetParametersResponse {
+    let values = req
+      .names
+      .iter()
+      .map(|name| self.parameters.get(name).cloned().unwrap_or(ParameterValue::NotSet).into())
+      .collect();
+    raw::GetParametersResponse { values }
+  }
+
+  pub fn on_get_parameter_types(&self, req: raw::GetParameterTypesRequest) -> raw::GetParameterTypesResponse {
+    let types = req
+      .names
+      .iter()
+      .map(|name| {
+        self
+          .parameters
+          .get(name)
+          .map(ParameterValue::to_parameter_type_enum)
+          .unwrap_or(raw::ParameterType::NOT_SET)
+      })
+      .collect();
+    raw::GetParameterTypesResponse { types }
+  }
+
+  // The validation hook judges the whole batch at once, so we do not support
+  // per-parameter rejection within one set_parameters call: either every
+  // requested parameter is applied, or every result reports the same reason.
+  // Returns the response together with the ParameterEvent to publish. The
+  // event is Some on every accepted request, even when it lists no
+  // parameters, and None when the validator rejects the request.
+  pub fn on_set_parameters(
+    &mut self,
+    req: raw::SetParametersRequest,
+  ) -> (raw::SetParametersResponse, Option<raw::ParameterEvent>) {
+    let parameters: Vec<Parameter> = req.parameters.into_iter().map(Parameter::from).collect();
+    match self.validate(&parameters) {
+      Ok(()) => {
+        let event = self.apply_all(&parameters);
+        let results = parameters.iter().map(|_| SetParametersResult::Ok(()).into()).collect();
+        (raw::SetParametersResponse { results }, Some(event))
+      }
+      Err(reason) => {
+        let results = parameters.iter().map(|_| SetParametersResult::Err(reason.clone()).into()).collect();
+        (raw::SetParametersResponse { results }, None)
+      }
+    }
+  }
+
+  pub fn on_set_parameters_atomically(
+    &mut self,
+    req: raw::SetParametersAtomicallyRequest,
+  ) -> (raw::SetParametersAtomicallyResponse, Option<raw::ParameterEvent>) {
+    let parameters: Vec<Parameter> = req.parameters.into_iter().map(Parameter::from).collect();
+    match self.validate(&parameters) {
+      Ok(()) => {
+        let event = self.apply_all(&parameters);
+        let result: raw::SetParametersResult = SetParametersResult::Ok(()).into();
+        (raw::SetParametersAtomicallyResponse { result }, Some(event))
+      }
+      Err(reason) => {
+        let result: raw::SetParametersResult = SetParametersResult::Err(reason).into();
+        (raw::SetParametersAtomicallyResponse { result }, None)
+      }
+    }
+  }
+
+  pub fn on_list_parameters(&self, req: raw::ListParametersRequest) -> raw::ListParametersResponse {
+    let names = self
+      .parameters
+      .keys()
+      .filter(|name| req.prefixes.is_empty() || req.prefixes.iter().any(|pfx| matches_prefix(name, pfx)))
+      .filter(|name| matches_depth(name, &req.prefixes, req.depth))
+      .cloned()
+      .collect();
+    raw::ListParametersResponse { result: raw::ListParametersResult { names, prefixes: req.prefixes } }
+  }
+
+  pub fn on_describe_parameters(&self, req: raw::DescribeParametersRequest) -> raw::DescribeParametersResponse {
+    let descriptors = req
+      .names
+      .iter()
+      .map(|name| raw::ParameterDescriptor {
+        name: name.clone(),
+        ptype: self
+          .parameters
+          .get(name)
+          .map(ParameterValue::to_parameter_type_enum)
+          .unwrap_or(raw::ParameterType::NOT_SET),
+        description: String::new(),
+        additional_constraints: String::new(),
+        read_only: false,
+        dynamic_typing: true,
+      })
+      .collect();
+    raw::DescribeParametersResponse { descriptors }
+  }
+}
+
+// A request/response pair for one of the six standard parameter services.
+// The response is correlated back to its request via the request's
+// SampleIdentity, the same mechanism RPC services use elsewhere in DDS.
+struct ServiceEndpoint<Req, Resp: serde::Serialize> {
+  requests: Subscription<Req>,
+  responses: Publisher<Resp>,
+}
+
+impl<Req: 'static + serde::de::DeserializeOwned, Resp: serde::Serialize> ServiceEndpoint<Req, Resp> {
+  async fn next_request(&self) -> ReadResult<(Req, MessageInfo)> {
+    self.requests.async_take().await
+  }
+
+  async fn respond(&self, response: Resp, request_info: &MessageInfo) {
+    let wo = WriteOptionsBuilder::new()
+      .related_sample_identity(request_info.sample_identity())
+      .build();
+    let _ = self.responses.async_publish_with_options(response, wo).await;
+  }
+}
+
+/// Request/response handler for the six standard ROS2 `rcl_interfaces`
+/// parameter services: `get_parameters`, `get_parameter_types`,
+/// `set_parameters`, `set_parameters_atomically`, `list_parameters`, and
+/// `describe_parameters`.
+///
+/// Constructed by [`Node`](crate::Node), which wires up the six DDS
+/// request/response topics (named following the usual ROS2
+/// `<node>/get_parameters` etc. convention) and the `/parameter_events`
+/// publisher. Running [`Self::spin`] answers incoming requests and publishes
+/// a [`raw::ParameterEvent`] whenever the parameter set changes, making this
+/// node visible to `ros2 param` and rqt. The actual parameter values and
+/// validation live in [`ParameterStore`], reachable via [`Self::store`] and
+/// [`Self::store_mut`].
+pub struct ParameterServer {
+  store: ParameterStore,
+
+  get_parameters: ServiceEndpoint<raw::GetParametersRequest, raw::GetParametersResponse>,
+  get_parameter_types: ServiceEndpoint<raw::GetParameterTypesRequest, raw::GetParameterTypesResponse>,
+  set_parameters: ServiceEndpoint<raw::SetParametersRequest, raw::SetParametersResponse>,
+  set_parameters_atomically:
+    ServiceEndpoint<raw::SetParametersAtomicallyRequest, raw::SetParametersAtomicallyResponse>,
+  list_parameters: ServiceEndpoint<raw::ListParametersRequest, raw::ListParametersResponse>,
+  describe_parameters: ServiceEndpoint<raw::DescribeParametersRequest, raw::DescribeParametersResponse>,
+
+  parameter_events: Publisher<raw::ParameterEvent>,
+}
+
+impl ParameterServer {
+  // These must be created from Node.
+  #[allow(clippy::too_many_arguments)]
+  pub(crate) fn new(
+    node_fqn: String,
+    get_parameters: (Subscription<raw::GetParametersRequest>, Publisher<raw::GetParametersResponse>),
+    get_parameter_types: (
+      Subscription<raw::GetParameterTypesRequest>,
+      Publisher<raw::GetParameterTypesResponse>,
+    ),
+    set_parameters: (Subscription<raw::SetParametersRequest>, Publisher<raw::SetParametersResponse>),
+    set_parameters_atomically: (
+      Subscription<raw::SetParametersAtomicallyRequest>,
+      Publisher<raw::SetParametersAtomicallyResponse>,
+    ),
+    list_parameters: (Subscription<raw::ListParametersRequest>, Publisher<raw::ListParametersResponse>),
+    describe_parameters: (
+      Subscription<raw::DescribeParametersRequest>,
+      Publisher<raw::DescribeParametersResponse>,
+    ),
+    parameter_events: Publisher<raw::ParameterEvent>,
+  ) -> ParameterServer {
+    ParameterServer {
+      store: ParameterStore::new(node_fqn),
+      get_parameters: ServiceEndpoint { requests: get_parameters.0, responses: get_parameters.1 },
+      get_parameter_types: ServiceEndpoint {
+        requests: get_parameter_types.0,
+        responses: get_parameter_types.1,
+      },
+      set_parameters: ServiceEndpoint { requests: set_parameters.0, responses: set_parameters.1 },
+      set_parameters_atomically: ServiceEndpoint {
+        requests: set_parameters_atomically.0,
+        responses: set_parameters_atomically.1,
+      },
+      list_parameters: ServiceEndpoint { requests: list_parameters.0, responses: list_parameters.1 },
+      describe_parameters: ServiceEndpoint {
+        requests: describe_parameters.0,
+        responses: describe_parameters.1,
+      },
+      parameter_events,
+    }
+  }
+
+  pub fn store(&self) -> &ParameterStore {
+    &self.store
+  }
+
+  pub fn store_mut(&mut self) -> &mut ParameterStore {
+    &mut self.store
+  }
+
+  /// Answers parameter service requests until the underlying DDS readers
+  /// return an error. Intended to be spawned as one of the node's
+  /// background tasks.
+  pub async fn spin(&mut self) -> ReadResult<()> {
+    loop {
+      // select! requires every arm to be FusedFuture + Unpin. These are
+      // freshly-created async fn futures each iteration, so they must be
+      // boxed (for Unpin) and fused individually.
+      let mut get_parameters = Box::pin(self.get_parameters.next_request().fuse());
+      let mut get_parameter_types = Box::pin(self.get_parameter_types.next_request().fuse());
+      let mut set_parameters = Box::pin(self.set_parameters.next_request().fuse());
+      let mut set_parameters_atomically = Box::pin(self.set_parameters_atomically.next_request().fuse());
+      let mut list_parameters = Box::pin(self.list_parameters.next_request().fuse());
+      let mut describe_parameters = Box::pin(self.describe_parameters.next_request().fuse());
+
+      futures::select! {
+        r = get_parameters => {
+          let (req, info) = r?;
+          let resp = self.store.on_get_parameters(req);
+          self.get_parameters.respond(resp, &info).await;
+        }
+        r = get_parameter_types => {
+          let (req, info) = r?;
+          let resp = self.store.on_get_parameter_types(req);
+          self.get_parameter_types.respond(resp, &info).await;
+        }
+        r = set_parameters => {
+          let (req, info) = r?;
+          let (resp, event) = self.store.on_set_parameters(req);
+          if let Some(event) = event {
+            let _ = self.parameter_events.publish(event);
+          }
+          self.set_parameters.respond(resp, &info).await;
+        }
+        r = set_parameters_atomically => {
+          let (req, info) = r?;
+          let (resp, event) = self.store.on_set_parameters_atomically(req);
+          if let Some(event) = event {
+            let _ = self.parameter_events.publish(event);
+          }
+          self.set_parameters_atomically.respond(resp, &info).await;
+        }
+        r = list_parameters => {
+          let (req, info) = r?;
+          let resp = self.store.on_list_parameters(req);
+          self.list_parameters.respond(resp, &info).await;
+        }
+        r = describe_parameters => {
+          let (req, info) = r?;
+          let resp = self.store.on_describe_parameters(req);
+          self.describe_parameters.respond(resp, &info).await;
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn param(name: &str, value: ParameterValue) -> Parameter {
+    Parameter { name: name.to_string(), value }
+  }
+
+  #[test]
+  fn get_parameters_returns_not_set_for_unknown_names() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+    store.initialize([param("a", ParameterValue::Integer(1))]);
+
+    let resp = store.on_get_parameters(raw::GetParametersRequest {
+      names: vec!["a".to_string(), "missing".to_string()],
+    });
+
+    assert_eq!(resp.values.len(), 2);
+    assert_eq!(ParameterValue::from(resp.values[0].clone()), ParameterValue::Integer(1));
+    assert_eq!(ParameterValue::from(resp.values[1].clone()), ParameterValue::NotSet);
+  }
+
+  #[test]
+  fn set_parameters_rejected_by_validator_does_not_change_store() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+    store.initialize([param("a", ParameterValue::Integer(1))]);
+    store.set_validation_callback(|_| Err("no".to_string()));
+
+    let (resp, event) =
+      store.on_set_parameters(raw::SetParametersRequest { parameters: vec![raw::Parameter::from(param(
+        "a",
+        ParameterValue::Integer(2),
+      ))] });
+
+    assert!(event.is_none());
+    assert!(resp.results.iter().all(|r| !r.successful));
+    let after = store.on_get_parameters(raw::GetParametersRequest { names: vec!["a".to_string()] });
+    assert_eq!(ParameterValue::from(after.values[0].clone()), ParameterValue::Integer(1));
+  }
+
+  #[test]
+  fn set_parameters_atomically_applies_all_and_emits_event() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+
+    let (resp, event) = store.on_set_parameters_atomically(raw::SetParametersAtomicallyRequest {
+      parameters: vec![
+        raw::Parameter::from(param("a", ParameterValue::Integer(1))),
+        raw::Parameter::from(param("b", ParameterValue::Boolean(true))),
+      ],
+    });
+
+    assert!(resp.result.successful);
+    let event = event.expect("applying parameters should emit a ParameterEvent");
+    assert_eq!(event.new_parameters.len(), 2);
+    assert!(event.changed_parameters.is_empty());
+  }
+
+  #[test]
+  fn list_parameters_depth_limits_to_requested_nesting() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+    store.initialize([
+      param("motor.left.gain", ParameterValue::Double(1.0)),
+      param("motor.left.gain.trim", ParameterValue::Double(0.1)),
+      param("motor.right.gain", ParameterValue::Double(1.0)),
+    ]);
+
+    let resp = store.on_list_parameters(raw::ListParametersRequest {
+      prefixes: vec!["motor.left".to_string()],
+      depth: 1,
+    });
+
+    assert_eq!(resp.result.names, vec!["motor.left.gain".to_string()]);
+  }
+
+  #[test]
+  fn list_parameters_recursive_depth_returns_everything_under_prefix() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+    store.initialize([
+      param("motor.left.gain", ParameterValue::Double(1.0)),
+      param("motor.left.gain.trim", ParameterValue::Double(0.1)),
+      param("motor.right.gain", ParameterValue::Double(1.0)),
+    ]);
+
+    let resp = store.on_list_parameters(raw::ListParametersRequest {
+      prefixes: vec!["motor.left".to_string()],
+      depth: raw::LIST_PARAMETERS_DEPTH_RECURSIVE,
+    });
+
+    let mut names = resp.result.names;
+    names.sort();
+    assert_eq!(names, vec!["motor.left.gain".to_string(), "motor.left.gain.trim".to_string()]);
+  }
+
+  #[test]
+  fn list_parameters_prefix_match_requires_a_component_boundary() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+    store.initialize([
+      param("motor.left.gain", ParameterValue::Double(1.0)),
+      param("motor.left2.gain", ParameterValue::Double(1.0)),
+    ]);
+
+    let resp = store.on_list_parameters(raw::ListParametersRequest {
+      prefixes: vec!["motor.le".to_string()],
+      depth: raw::LIST_PARAMETERS_DEPTH_RECURSIVE,
+    });
+
+    assert!(resp.result.names.is_empty());
+  }
+
+  #[test]
+  fn setting_a_parameter_to_not_set_undeclares_it() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+    store.initialize([param("a", ParameterValue::Integer(1))]);
+
+    let (resp, event) = store.on_set_parameters(raw::SetParametersRequest {
+      parameters: vec![raw::Parameter::from(param("a", ParameterValue::NotSet))],
+    });
+
+    assert!(resp.results[0].successful);
+    let event = event.expect("undeclaring a parameter should emit a ParameterEvent");
+    assert_eq!(event.deleted_parameters.len(), 1);
+    assert_eq!(event.deleted_parameters[0].name, "a");
+    assert!(event.new_parameters.is_empty());
+    assert!(event.changed_parameters.is_empty());
+
+    let listed = store.on_list_parameters(raw::ListParametersRequest {
+      prefixes: Vec::new(),
+      depth: raw::LIST_PARAMETERS_DEPTH_RECURSIVE,
+    });
+    assert!(listed.result.names.is_empty());
+  }
+
+  #[test]
+  fn undeclaring_an_unknown_parameter_reports_no_deletion() {
+    let mut store = ParameterStore::new("/test_node".to_string());
+
+    let (resp, event) = store.on_set_parameters(raw::SetParametersRequest {
+      parameters: vec![raw::Parameter::from(param("missing", ParameterValue::NotSet))],
+    });
+
+    assert!(resp.results[0].successful);
+    let event = event.expect("on_set_parameters always emits an event on success");
+    assert!(event.deleted_parameters.is_empty());
+  }
+}
@@ -0,0 +1,306 @@
+use std::{collections::HashMap, fs, path::Path, path::PathBuf};
+
+use futures::{channel::mpsc, Stream, StreamExt};
+use notify::{RecursiveMode, Watcher};
+use rustdds::Timestamp;
+
+use crate::parameters::{raw, Parameter, ParameterValue, PARAMETER_SEPARATOR};
+
+/// Error produced while loading or watching a ROS2 parameter YAML file.
+#[derive(Debug)]
+pub enum ParameterYamlError {
+  Io(std::io::Error),
+  Yaml(serde_yaml::Error),
+  /// The file does not contain a `ros__parameters` section for the
+  /// requested node name (or for the `/**` wildcard).
+  NodeNotFound(String),
+  Watch(notify::Error),
+}
+
+impl std::fmt::Display for ParameterYamlError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ParameterYamlError::Io(e) => write!(f, "I/O error reading parameter file: {e}"),
+      ParameterYamlError::Yaml(e) => write!(f, "malformed parameter YAML: {e}"),
+      ParameterYamlError::NodeNotFound(node) => {
+        write!(f, "parameter file has no ros__parameters section for node \"{node}\" or \"/**\"")
+      }
+      ParameterYamlError::Watch(e) => write!(f, "cannot watch parameter file: {e}"),
+    }
+  }
+}
+
+impl std::error::Error for ParameterYamlError {}
+
+impl From<std::io::Error> for ParameterYamlError {
+  fn from(e: std::io::Error) -> Self {
+    ParameterYamlError::Io(e)
+  }
+}
+
+impl From<serde_yaml::Error> for ParameterYamlError {
+  fn from(e: serde_yaml::Error) -> Self {
+    ParameterYamlError::Yaml(e)
+  }
+}
+
+/// Reads a standard ROS2 parameter YAML file
+/// (`<node_name>: { ros__parameters: { key: value, ... } }`) and returns the
+/// parameters declared for `node_name`, falling back to the `/**` wildcard
+/// node if `node_name` has no section of its own.
+///
+/// `Node::load_parameters_from_file` calls this with the node's own
+/// fully-qualified name.
+pub fn load_parameters_from_file(path: impl AsRef<Path>, node_name: &str) -> Result<Vec<Parameter>, ParameterYamlError> {
+  let text = fs::read_to_string(path)?;
+  parse_parameter_yaml(&text, node_name)
+}
+
+/// As [`load_parameters_from_file`], but parses an already-read YAML string.
+pub fn parse_parameter_yaml(yaml: &str, node_name: &str) -> Result<Vec<Parameter>, ParameterYamlError> {
+  let doc: serde_yaml::Value = serde_yaml::from_str(yaml)?;
+  let nodes = doc.as_mapping().ok_or_else(|| ParameterYamlError::NodeNotFound(node_name.to_string()))?;
+
+  let ros_parameters = nodes
+    .get(serde_yaml::Value::String(node_name.to_string()))
+    .or_else(|| nodes.get(serde_yaml::Value::String("/**".to_string())))
+    .and_then(|node| node.get("ros__parameters"))
+    .ok_or_else(|| ParameterYamlError::NodeNotFound(node_name.to_string()))?;
+
+  let mut parameters = Vec::new();
+  flatten(ros_parameters, String::new(), &mut parameters);
+  Ok(parameters)
+}
+
+// Recursively walks nested mappings, joining keys with '.', the same
+// separator rclcpp/rclpy use for nested parameter YAML (e.g.
+// `motor.left.gain`), and turns every leaf scalar/sequence into a Parameter.
+fn flatten(value: &serde_yaml::Value, prefix: String, out: &mut Vec<Parameter>) {
+  match value {
+    serde_yaml::Value::Mapping(map) => {
+      for (k, v) in map {
+        let Some(key) = k.as_str() else { continue };
+        let name = if prefix.is_empty() { key.to_string() } else { format!("{prefix}{PARAMETER_SEPARATOR}{key}") };
+        flatten(v, name, out);
+      }
+    }
+    other => {
+      if let Some(value) = scalar_or_array_to_parameter_value(other) {
+        out.push(Parameter { name: prefix, value });
+      }
+    }
+  }
+}
+
+fn scalar_or_array_to_parameter_value(value: &serde_yaml::Value) -> Option<ParameterValue> {
+  match value {
+    serde_yaml::Value::Null => Some(ParameterValue::NotSet),
+    serde_yaml::Value::Bool(b) => Some(ParameterValue::Boolean(*b)),
+    serde_yaml::Value::Number(n) => {
+      if let Some(i) = n.as_i64() {
+        Some(ParameterValue::Integer(i))
+      } else {
+        n.as_f64().map(ParameterValue::Double)
+      }
+    }
+    serde_yaml::Value::String(s) => Some(ParameterValue::String(s.clone())),
+    serde_yaml::Value::Sequence(seq) => array_to_parameter_value(seq),
+    serde_yaml::Value::Tagged(t) => scalar_or_array_to_parameter_value(&t.value),
+  }
+}
+
+// A YAML sequence maps onto exactly one of the *Array ParameterValue
+// variants, inferred from its first element; ROS2 parameter arrays are
+// homogeneous, so this matches the upstream loader's behavior.
+fn array_to_parameter_value(seq: &[serde_yaml::Value]) -> Option<ParameterValue> {
+  match seq.first()? {
+    serde_yaml::Value::Bool(_) => {
+      Some(ParameterValue::BooleanArray(seq.iter().filter_map(|v| v.as_bool()).collect()))
+    }
+    serde_yaml::Value::Number(n) if n.as_i64().is_some() => {
+      Some(ParameterValue::IntegerArray(seq.iter().filter_map(|v| v.as_i64()).collect()))
+    }
+    serde_yaml::Value::Number(_) => {
+      Some(ParameterValue::DoubleArray(seq.iter().filter_map(|v| v.as_f64()).collect()))
+    }
+    serde_yaml::Value::String(_) => Some(ParameterValue::StringArray(
+      seq.iter().filter_map(|v| v.as_str().map(str::to_string)).collect(),
+    )),
+    _ => None,
+  }
+}
+
+// Diffs the previous and newly-loaded parameter sets into a ParameterEvent,
+// the same shape ParameterStore::apply_all emits for live updates.
+fn diff(node: &str, before: &HashMap<String, ParameterValue>, after: &[Parameter]) -> raw::ParameterEvent {
+  let after_map: HashMap<&str, &ParameterValue> = after.iter().map(|p| (p.name.as_str(), &p.value)).collect();
+
+  let mut new_parameters = Vec::new();
+  let mut changed_parameters = Vec::new();
+  for p in after {
+    match before.get(&p.name) {
+      None => new_parameters.push(raw::Parameter::from(p.clone())),
+      Some(old) if old != &p.value => changed_parameters.push(raw::Parameter::from(p.clone())),
+      Some(_) => (),
+    }
+  }
+  let deleted_parameters = before
+    .keys()
+    .filter(|name| !after_map.contains_key(name.as_str()))
+    .map(|name| raw::Parameter { name: name.clone(), value: ParameterValue::NotSet.into() })
+    .collect();
+
+  raw::ParameterEvent {
+    timestamp: Timestamp::now(),
+    node: node.to_string(),
+    new_parameters,
+    changed_parameters,
+    deleted_parameters,
+  }
+}
+
+/// Watches `path` for changes and yields a [`raw::ParameterEvent`] each time
+/// the file is rewritten, describing exactly what changed since the last
+/// read. The first read happens immediately and is reported as an event
+/// whose `new_parameters` is the whole file, so callers can initialize a
+/// [`ParameterServer`](crate::parameter_server::ParameterServer) purely by
+/// consuming this stream.
+///
+/// `Node::watch_parameter_file` calls this with the node's own
+/// fully-qualified name.
+pub fn watch_parameter_file(
+  path: impl Into<PathBuf>,
+  node_name: impl Into<String>,
+) -> Result<impl Stream<Item = raw::ParameterEvent>, ParameterYamlError> {
+  let path = path.into();
+  let node_name = node_name.into();
+
+  let (tx, rx) = mpsc::unbounded();
+  let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+    if let Ok(event) = res {
+      if event.kind.is_modify() || event.kind.is_create() {
+        let _ = tx.unbounded_send(());
+      }
+    }
+  })
+  .map_err(ParameterYamlError::Watch)?;
+  watcher.watch(&path, RecursiveMode::NonRecursive).map_err(ParameterYamlError::Watch)?;
+
+  let mut previous: HashMap<String, ParameterValue> = HashMap::new();
+  let initial = load_parameters_from_file(&path, &node_name)?;
+  let initial_event = diff(&node_name, &previous, &initial);
+  for p in &initial {
+    previous.insert(p.name.clone(), p.value.clone());
+  }
+
+  // `watcher` must outlive the returned stream, or the OS-level watch is
+  // dropped and no further events arrive; keeping it in the closure's
+  // capture does that.
+  let changes = rx.filter_map(move |()| {
+    let node_name = node_name.clone();
+    let path = path.clone();
+    let event = load_parameters_from_file(&path, &node_name).ok().map(|loaded| {
+      let event = diff(&node_name, &previous, &loaded);
+      previous.clear();
+      previous.extend(loaded.into_iter().map(|p| (p.name, p.value)));
+      event
+    });
+    async move { event }
+  });
+
+  Ok(futures::stream::once(async move { initial_event })
+    .chain(changes)
+    .map(move |event| {
+      let _keep_alive = &watcher;
+      event
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn names(parameters: &[Parameter]) -> Vec<&str> {
+    let mut names: Vec<&str> = parameters.iter().map(|p| p.name.as_str()).collect();
+    names.sort_unstable();
+    names
+  }
+
+  #[test]
+  fn flattens_nested_keys_with_the_parameter_separator() {
+    let yaml = r#"
+my_node:
+  ros__parameters:
+    motor:
+      left:
+        gain: 1.5
+      right:
+        gain: 2.5
+    enabled: true
+"#;
+    let parameters = parse_parameter_yaml(yaml, "my_node").unwrap();
+    assert_eq!(names(&parameters), vec!["enabled", "motor.left.gain", "motor.right.gain"]);
+    let gain = parameters.iter().find(|p| p.name == "motor.left.gain").unwrap();
+    assert_eq!(gain.value, ParameterValue::Double(1.5));
+  }
+
+  #[test]
+  fn falls_back_to_wildcard_node_when_node_name_is_absent() {
+    let yaml = r#"
+/**:
+  ros__parameters:
+    rate: 10
+"#;
+    let parameters = parse_parameter_yaml(yaml, "/some/other_node").unwrap();
+    assert_eq!(parameters.len(), 1);
+    assert_eq!(parameters[0].name, "rate");
+    assert_eq!(parameters[0].value, ParameterValue::Integer(10));
+  }
+
+  #[test]
+  fn missing_node_section_is_an_error() {
+    let yaml = "other_node:\n  ros__parameters:\n    x: 1\n";
+    let err = parse_parameter_yaml(yaml, "my_node").unwrap_err();
+    assert!(matches!(err, ParameterYamlError::NodeNotFound(node) if node == "my_node"));
+  }
+
+  #[test]
+  fn infers_homogeneous_array_types() {
+    let yaml = r#"
+my_node:
+  ros__parameters:
+    ints: [1, 2, 3]
+    strs: ["a", "b"]
+    bools: [true, false]
+    doubles: [1.0, 2.5]
+"#;
+    let parameters = parse_parameter_yaml(yaml, "my_node").unwrap();
+    let get = |name: &str| parameters.iter().find(|p| p.name == name).unwrap().value.clone();
+    assert_eq!(get("ints"), ParameterValue::IntegerArray(vec![1, 2, 3]));
+    assert_eq!(get("strs"), ParameterValue::StringArray(vec!["a".to_string(), "b".to_string()]));
+    assert_eq!(get("bools"), ParameterValue::BooleanArray(vec![true, false]));
+    assert_eq!(get("doubles"), ParameterValue::DoubleArray(vec![1.0, 2.5]));
+  }
+
+  #[test]
+  fn diff_detects_new_changed_and_deleted_parameters() {
+    let mut before = HashMap::new();
+    before.insert("kept".to_string(), ParameterValue::Integer(1));
+    before.insert("changed".to_string(), ParameterValue::Integer(1));
+    before.insert("removed".to_string(), ParameterValue::Integer(1));
+
+    let after = vec![
+      Parameter { name: "kept".to_string(), value: ParameterValue::Integer(1) },
+      Parameter { name: "changed".to_string(), value: ParameterValue::Integer(2) },
+      Parameter { name: "added".to_string(), value: ParameterValue::Integer(3) },
+    ];
+
+    let event = diff("/my_node", &before, &after);
+    assert_eq!(event.new_parameters.len(), 1);
+    assert_eq!(event.new_parameters[0].name, "added");
+    assert_eq!(event.changed_parameters.len(), 1);
+    assert_eq!(event.changed_parameters[0].name, "changed");
+    assert_eq!(event.deleted_parameters.len(), 1);
+    assert_eq!(event.deleted_parameters[0].name, "removed");
+  }
+}
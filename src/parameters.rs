@@ -7,7 +7,7 @@ pub struct Parameter {
 
 /// Rust-like representation of ROS2
 /// [ParameterValue](https://github.com/ros2/rcl_interfaces/blob/master/rcl_interfaces/msg/ParameterValue.msg)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParameterValue {
   NotSet,
   Boolean(bool),
@@ -218,4 +218,113 @@ pub mod raw {
     pub successful: bool,
     pub reason: String,
   }
+
+  /// [ParameterDescriptor](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/msg/ParameterDescriptor.msg)
+  ///
+  /// The numeric/array range constraint fields (`floating_point_range`,
+  /// `integer_range`) are not modeled yet.
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct ParameterDescriptor {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ptype: u8,
+    pub description: String,
+    pub additional_constraints: String,
+    pub read_only: bool,
+    pub dynamic_typing: bool,
+  }
+
+  // The six standard rcl_interfaces parameter services. Each Request/Response
+  // pair mirrors the corresponding .srv file under
+  // https://github.com/ros2/rcl_interfaces/tree/rolling/rcl_interfaces/srv
+
+  /// [GetParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/GetParameters.srv) request
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct GetParametersRequest {
+    pub names: Vec<String>,
+  }
+
+  /// [GetParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/GetParameters.srv) response
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct GetParametersResponse {
+    pub values: Vec<ParameterValue>,
+  }
+
+  /// [GetParameterTypes.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/GetParameterTypes.srv) request
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct GetParameterTypesRequest {
+    pub names: Vec<String>,
+  }
+
+  /// [GetParameterTypes.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/GetParameterTypes.srv) response
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct GetParameterTypesResponse {
+    pub types: Vec<u8>,
+  }
+
+  /// [SetParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/SetParameters.srv) request
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct SetParametersRequest {
+    pub parameters: Vec<Parameter>,
+  }
+
+  /// [SetParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/SetParameters.srv) response
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct SetParametersResponse {
+    pub results: Vec<SetParametersResult>,
+  }
+
+  /// [SetParametersAtomically.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/SetParametersAtomically.srv) request
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct SetParametersAtomicallyRequest {
+    pub parameters: Vec<Parameter>,
+  }
+
+  /// [SetParametersAtomically.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/SetParametersAtomically.srv) response
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct SetParametersAtomicallyResponse {
+    pub result: SetParametersResult,
+  }
+
+  /// [ListParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/ListParameters.srv) request
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct ListParametersRequest {
+    pub prefixes: Vec<String>,
+    pub depth: u64,
+  }
+
+  /// [ListParametersResult](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/msg/ListParametersResult.msg)
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct ListParametersResult {
+    pub names: Vec<String>,
+    pub prefixes: Vec<String>,
+  }
+
+  /// [ListParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/ListParameters.srv) response
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct ListParametersResponse {
+    pub result: ListParametersResult,
+  }
+
+  /// [DescribeParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/DescribeParameters.srv) request
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct DescribeParametersRequest {
+    pub names: Vec<String>,
+  }
+
+  /// [DescribeParameters.srv](https://github.com/ros2/rcl_interfaces/blob/rolling/rcl_interfaces/srv/DescribeParameters.srv) response
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct DescribeParametersResponse {
+    pub descriptors: Vec<ParameterDescriptor>,
+  }
+
+  /// Whole-parameter-set depth: `list_parameters` should not prune by prefix
+  /// depth at all.
+  pub const LIST_PARAMETERS_DEPTH_RECURSIVE: u64 = 0;
 }
+
+/// Separator between nested parameter name components, e.g. the `.` in
+/// `motor.left.gain`. Used both when flattening nested parameter YAML
+/// ([`crate::parameter_yaml`]) and when depth-limiting `list_parameters`
+/// ([`crate::parameter_server`]).
+pub const PARAMETER_SEPARATOR: char = '.';
@@ -2,6 +2,7 @@ use std::io;
 
 use mio::{Evented, Poll, PollOpt, Ready, Token};
 use futures::{
+  future::{poll_immediate, select_all},
   pin_mut,
   stream::{FusedStream, Stream, StreamExt},
 };
@@ -75,6 +76,17 @@ impl<M: Serialize> Publisher<M> {
 ///
 /// Corresponds to a (simplified) [`DataReader`](rustdds::no_key::DataReader) in
 /// DDS
+///
+/// [`Self::async_take`]/[`Self::async_stream`] only ever yield data samples.
+/// An earlier attempt at this crate added a `SubscriptionStreamEvent` that
+/// additionally interleaved `PublisherMatched`/`PublisherUnmatched`/
+/// `LivenessLost`, but it only ever produced `Data(...)` — the lifecycle
+/// variants were declared and never constructed. That was removed rather
+/// than kept as a stub. Implementing it for real needs `SimpleDataReaderCdr`
+/// to expose `rustdds`'s DDS status-change events (`SubscriptionMatched`,
+/// `LivelinessChanged`, ...) alongside its data stream, which this snapshot
+/// of the crate does not do; this is left as a follow-up, not delivered
+/// here.
 pub struct Subscription<M> {
   datareader: no_key::SimpleDataReaderCdr<M>,
 }
@@ -128,6 +140,113 @@ impl<M: 'static + DeserializeOwned> Subscription<M> {
   }
 }
 
+// Pulls one value out of whichever of `subscriptions[missing]` resolves
+// first, so a stream stalled behind a silent publisher never blocks the
+// others. Only the indices in `missing` have their `pending` slot empty.
+async fn fill_one_pending<M>(
+  subscriptions: &[Subscription<M>],
+  pending: &mut [Option<(M, MessageInfo)>],
+) -> ReadResult<()>
+where
+  M: 'static + DeserializeOwned,
+{
+  let missing: Vec<usize> = pending
+    .iter()
+    .enumerate()
+    .filter_map(|(i, slot)| slot.is_none().then_some(i))
+    .collect();
+  if missing.is_empty() {
+    return Ok(());
+  }
+  let futures: Vec<_> = missing.iter().map(|&i| Box::pin(subscriptions[i].async_take())).collect();
+  let (result, which, _still_pending) = select_all(futures).await;
+  pending[missing[which]] = Some(result?);
+  Ok(())
+}
+
+// Opportunistically fills every still-empty slot whose subscription already
+// has a sample sitting in its reader right now, without waiting on any
+// subscription that doesn't. Used so a publisher that's gone silent (or was
+// never matched) can't hold back samples that are already available from
+// its siblings.
+async fn fill_ready_pending<M>(
+  subscriptions: &[Subscription<M>],
+  pending: &mut [Option<(M, MessageInfo)>],
+) -> ReadResult<()>
+where
+  M: 'static + DeserializeOwned,
+{
+  for (i, slot) in pending.iter_mut().enumerate() {
+    if slot.is_none() {
+      if let Some(result) = poll_immediate(subscriptions[i].async_take()).await {
+        *slot = Some(result?);
+      }
+    }
+  }
+  Ok(())
+}
+
+// Picks the slot with the earliest source_timestamp, preferring samples
+// that carry one; ties and missing timestamps fall back to subscription
+// order (lowest index).
+fn earliest_pending_index<M>(pending: &[Option<(M, MessageInfo)>]) -> usize {
+  pending
+    .iter()
+    .enumerate()
+    .filter_map(|(i, slot)| slot.as_ref().map(|(_, info)| (i, info.source_timestamp())))
+    // Samples without a source_timestamp sort after every timestamped one,
+    // and simply keep subscription order (lowest index) among themselves.
+    .min_by_key(|(i, ts)| (ts.is_none(), *ts, *i))
+    .map(|(i, _)| i)
+    .expect("earliest_pending_index called with no pending samples")
+}
+
+/// Fuses several same-typed subscriptions into one `Stream`, so a node
+/// subscribed to many same-type topics (e.g. several sensors) can drain them
+/// all from a single `.next().await` loop instead of polling each
+/// `Subscription` separately. Samples are emitted in `source_timestamp`
+/// order among whatever is currently buffered, at most one pending sample
+/// per subscription.
+///
+/// This never waits on every subscription before emitting: a subscription
+/// that's slow, silent, or has no matched publisher at all would otherwise
+/// stall the whole merge indefinitely, which defeats the point of merging
+/// many sensor topics in the first place. Instead, each round opportunistically
+/// grabs whatever samples are already available (non-blocking) and only
+/// blocks for a new one if nothing at all is buffered yet; as soon as one
+/// slot is filled, the merge stops waiting on the rest and emits from what
+/// it has. The trade-off is that ordering is best-effort, not a strict
+/// guarantee, when a source is lagging behind the others.
+///
+/// `Node::merged_subscription_stream` is a thin wrapper around this.
+pub fn merged_subscription_stream<M>(
+  subscriptions: Vec<Subscription<M>>,
+) -> impl Stream<Item = ReadResult<(M, MessageInfo)>>
+where
+  M: 'static + DeserializeOwned,
+{
+  let pending_len = subscriptions.len();
+  futures::stream::unfold(
+    (subscriptions, (0..pending_len).map(|_| None).collect::<Vec<_>>()),
+    |(subscriptions, mut pending)| async move {
+      if subscriptions.is_empty() {
+        return None;
+      }
+      if let Err(e) = fill_ready_pending(&subscriptions, &mut pending).await {
+        return Some((Err(e), (subscriptions, pending)));
+      }
+      while pending.iter().all(Option::is_none) {
+        if let Err(e) = fill_one_pending(&subscriptions, &mut pending).await {
+          return Some((Err(e), (subscriptions, pending)));
+        }
+      }
+      let i = earliest_pending_index(&pending);
+      let sample = pending[i].take().expect("checked Some above");
+      Some((Ok(sample), (subscriptions, pending)))
+    },
+  )
+}
+
 // helper
 #[inline]
 fn dcc_to_value_and_messageinfo<M>(dcc: no_key::DeserializedCacheChange<M>) -> (M, MessageInfo)
@@ -138,6 +257,47 @@ where
   (dcc.into_value(), mi)
 }
 
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // earliest_pending_index only looks at the MessageInfo half of each
+  // pending slot, so these can be built without a real Subscription/sample.
+  fn slot(source_timestamp: Option<Timestamp>) -> Option<((), MessageInfo)> {
+    Some((
+      (),
+      MessageInfo {
+        received_timestamp: Timestamp::now(),
+        source_timestamp,
+        sequence_number: SequenceNumber::default(),
+        publisher: GUID::GUID_UNKNOWN,
+        related_sample_identity: None,
+      },
+    ))
+  }
+
+  #[test]
+  fn picks_the_slot_with_the_earliest_source_timestamp() {
+    let early = Timestamp::now();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let late = Timestamp::now();
+    let pending = vec![slot(Some(late)), slot(Some(early))];
+    assert_eq!(earliest_pending_index(&pending), 1);
+  }
+
+  #[test]
+  fn untimestamped_slots_sort_after_timestamped_ones() {
+    let pending = vec![slot(None), slot(Some(Timestamp::now()))];
+    assert_eq!(earliest_pending_index(&pending), 1);
+  }
+
+  #[test]
+  fn ties_fall_back_to_lowest_subscription_index() {
+    let pending: Vec<Option<((), MessageInfo)>> = vec![slot(None), slot(None)];
+    assert_eq!(earliest_pending_index(&pending), 0);
+  }
+}
+
 impl<D> Evented for Subscription<D>
 where
   D: DeserializeOwned,
@@ -201,7 +361,7 @@ impl MessageInfo {
 impl From<&SampleInfo> for MessageInfo {
   fn from(sample_info: &SampleInfo) -> MessageInfo {
     MessageInfo {
-      received_timestamp: Timestamp::ZERO, // TODO!
+      received_timestamp: Timestamp::now(),
       source_timestamp: sample_info.source_timestamp(),
       sequence_number: sample_info.sample_identity().sequence_number,
       publisher: sample_info.publication_handle(), // DDS has an odd name for this
@@ -213,7 +373,7 @@ impl From<&SampleInfo> for MessageInfo {
 impl<M> From<&rustdds::no_key::DeserializedCacheChange<M>> for MessageInfo {
   fn from(dcc: &rustdds::no_key::DeserializedCacheChange<M>) -> MessageInfo {
     MessageInfo {
-      received_timestamp: Timestamp::ZERO, // TODO!
+      received_timestamp: Timestamp::now(),
       source_timestamp: dcc.source_timestamp(),
       sequence_number: dcc.sequence_number,
       publisher: dcc.writer_guid(),
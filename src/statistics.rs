@@ -0,0 +1,257 @@
+use rustdds::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use crate::pubsub::{MessageInfo, Publisher};
+
+/// Running count/mean/min/max/stddev over a stream of `f64` samples,
+/// computed with Welford's online algorithm so no sample history needs to be
+/// kept: `mean` is updated by `(x - mean) / n`, and `m2` (the running sum of
+/// squared deviations from the mean) by `(x - mean_before) * (x -
+/// mean_after)`; variance is `m2 / (n - 1)`.
+#[derive(Debug, Clone, Copy, Default)]
+struct RunningStats {
+  n: u64,
+  mean: f64,
+  m2: f64,
+  min: f64,
+  max: f64,
+}
+
+impl RunningStats {
+  fn update(&mut self, x: f64) {
+    if self.n == 0 {
+      self.min = x;
+      self.max = x;
+    } else {
+      self.min = self.min.min(x);
+      self.max = self.max.max(x);
+    }
+    self.n += 1;
+    let delta = x - self.mean;
+    self.mean += delta / self.n as f64;
+    let delta2 = x - self.mean;
+    self.m2 += delta * delta2;
+  }
+
+  fn stddev(&self) -> f64 {
+    if self.n < 2 {
+      0.0
+    } else {
+      (self.m2 / (self.n - 1) as f64).sqrt()
+    }
+  }
+}
+
+/// One row of a `statistics_msgs/MetricsMessage`: the running statistics for
+/// a single measurement (e.g. "message_age" or "period") over the current
+/// publication window.
+#[derive(Debug, Clone, Copy)]
+pub struct StatisticDataPoint {
+  pub average: f64,
+  pub min: f64,
+  pub max: f64,
+  pub stddev: f64,
+  pub sample_count: u64,
+}
+
+impl From<RunningStats> for StatisticDataPoint {
+  fn from(s: RunningStats) -> StatisticDataPoint {
+    StatisticDataPoint { average: s.mean, min: s.min, max: s.max, stddev: s.stddev(), sample_count: s.n }
+  }
+}
+
+/// Per-subscription message age (`received_timestamp - source_timestamp`)
+/// and inter-message period, accumulated since the last
+/// [`Self::take_window`] and published on `/statistics` in the standard
+/// `statistics_msgs/MetricsMessage` shape, so existing ROS2 latency/jitter
+/// tooling (`ros2 topic`, rqt) works against ros2-client nodes unchanged.
+pub struct TopicStatistics {
+  topic_name: String,
+  age: RunningStats,
+  period: RunningStats,
+  last_received: Option<Timestamp>,
+  window_start: Timestamp,
+}
+
+impl TopicStatistics {
+  pub fn new(topic_name: String) -> TopicStatistics {
+    TopicStatistics {
+      topic_name,
+      age: RunningStats::default(),
+      period: RunningStats::default(),
+      last_received: None,
+      window_start: Timestamp::now(),
+    }
+  }
+
+  /// Folds one received sample's timing into the running statistics. Call
+  /// this for every sample a subscription takes, e.g. alongside
+  /// [`Subscription::take`](crate::pubsub::Subscription::take).
+  pub fn observe(&mut self, info: &MessageInfo) {
+    if let Some(source) = info.source_timestamp() {
+      self.age.update(seconds_between(source, info.received_timestamp()));
+    }
+    if let Some(previous) = self.last_received {
+      self.period.update(seconds_between(previous, info.received_timestamp()));
+    }
+    self.last_received = Some(info.received_timestamp());
+  }
+
+  /// Snapshots the current window as a `MetricsMessage` for
+  /// `message_age`/`period`, and resets the running statistics (but not
+  /// `last_received`, so `period` keeps tracking across window boundaries).
+  pub fn take_window(&mut self, node_name: &str) -> raw::MetricsMessage {
+    let window_start = self.window_start;
+    let window_stop = Timestamp::now();
+    self.window_start = window_stop;
+
+    let statistics = vec![
+      raw::StatisticDataPoint { metric_name: "message_age".to_string(), data: std::mem::take(&mut self.age).into() },
+      raw::StatisticDataPoint {
+        metric_name: "period".to_string(),
+        data: std::mem::take(&mut self.period).into(),
+      },
+    ];
+
+    raw::MetricsMessage {
+      measurement_source_name: node_name.to_string(),
+      metrics_source: self.topic_name.clone(),
+      unit: "s".to_string(),
+      window_start,
+      window_stop,
+      statistics,
+    }
+  }
+}
+
+impl From<RunningStats> for raw::StatisticData {
+  fn from(s: RunningStats) -> raw::StatisticData {
+    let point: StatisticDataPoint = s.into();
+    raw::StatisticData {
+      average: point.average,
+      min: point.min,
+      max: point.max,
+      stddev: point.stddev,
+      sample_count: point.sample_count as f64,
+    }
+  }
+}
+
+// Subtracts in the integer nanosecond domain before converting to f64: at
+// current epoch-nanosecond magnitudes, f64's 52-bit mantissa only resolves
+// to ~100-250ns steps, which would quantize (and occasionally misorder) the
+// sub-millisecond age/period samples this module exists to measure.
+fn seconds_between(earlier: Timestamp, later: Timestamp) -> f64 {
+  (later.to_nanos() as i64 - earlier.to_nanos() as i64) as f64 / 1.0e9
+}
+
+/// Periodically takes each tracked topic's statistics window and publishes
+/// it on `/statistics`.
+pub struct StatisticsPublisher {
+  node_name: String,
+  publisher: Publisher<raw::MetricsMessage>,
+  topics: Vec<TopicStatistics>,
+}
+
+impl StatisticsPublisher {
+  // Must be created from Node, which owns the /statistics Publisher.
+  pub(crate) fn new(node_name: String, publisher: Publisher<raw::MetricsMessage>) -> StatisticsPublisher {
+    StatisticsPublisher { node_name, publisher, topics: Vec::new() }
+  }
+
+  pub fn track(&mut self, topic_name: String) -> usize {
+    self.topics.push(TopicStatistics::new(topic_name));
+    self.topics.len() - 1
+  }
+
+  pub fn observe(&mut self, handle: usize, info: &MessageInfo) {
+    self.topics[handle].observe(info);
+  }
+
+  /// Publishes one `MetricsMessage` per tracked topic and resets their
+  /// windows. Intended to be called on a timer at the node's configured
+  /// statistics publication interval.
+  pub fn publish_window(&mut self) {
+    for topic in &mut self.topics {
+      let message = topic.take_window(&self.node_name);
+      let _ = self.publisher.publish(message);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn running_stats_tracks_mean_min_max() {
+    let mut stats = RunningStats::default();
+    for x in [1.0, 2.0, 3.0, 4.0] {
+      stats.update(x);
+    }
+    assert_eq!(stats.n, 4);
+    assert_eq!(stats.min, 1.0);
+    assert_eq!(stats.max, 4.0);
+    assert!((stats.mean - 2.5).abs() < 1e-9);
+  }
+
+  #[test]
+  fn running_stats_stddev_matches_known_sample_variance() {
+    let mut stats = RunningStats::default();
+    // Sample variance of 2, 4, 4, 4, 5, 5, 7, 9 is 4.571428..., stddev ~2.1381.
+    for x in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+      stats.update(x);
+    }
+    assert!((stats.stddev() - 2.1380899352993950).abs() < 1e-9);
+  }
+
+  #[test]
+  fn running_stats_stddev_is_zero_with_fewer_than_two_samples() {
+    let mut stats = RunningStats::default();
+    assert_eq!(stats.stddev(), 0.0);
+    stats.update(42.0);
+    assert_eq!(stats.stddev(), 0.0);
+  }
+
+  #[test]
+  fn seconds_between_is_positive_for_a_later_timestamp() {
+    let earlier = Timestamp::now();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let later = Timestamp::now();
+    assert!(seconds_between(earlier, later) > 0.0);
+    assert_eq!(seconds_between(earlier, earlier), 0.0);
+  }
+}
+
+/// Wire-compatible `statistics_msgs` message types.
+pub mod raw {
+  use rustdds::Timestamp;
+  use serde::{Deserialize, Serialize};
+
+  /// [StatisticDataPoint](https://github.com/ros-tooling/libstatistics_collector/blob/main/statistics_msgs/msg/StatisticDataPoint.msg)
+  #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+  pub struct StatisticData {
+    pub average: f64,
+    pub min: f64,
+    pub max: f64,
+    pub stddev: f64,
+    pub sample_count: f64,
+  }
+
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct StatisticDataPoint {
+    pub metric_name: String,
+    pub data: StatisticData,
+  }
+
+  /// [MetricsMessage](https://github.com/ros-tooling/libstatistics_collector/blob/main/statistics_msgs/msg/MetricsMessage.msg)
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  pub struct MetricsMessage {
+    pub measurement_source_name: String,
+    pub metrics_source: String,
+    pub unit: String,
+    pub window_start: Timestamp,
+    pub window_stop: Timestamp,
+    pub statistics: Vec<StatisticDataPoint>,
+  }
+}